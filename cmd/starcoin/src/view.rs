@@ -0,0 +1,41 @@
+// Copyright (c) The Starcoin Core Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::wallet::ResourceView;
+use serde::{Deserialize, Serialize};
+use starcoin_types::account_address::AccountAddress;
+use starcoin_wallet_api::WalletAccount;
+
+/// A wallet account as rendered by the CLI.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct AccountView {
+    pub address: AccountAddress,
+    pub public_key: String,
+    pub is_default: bool,
+}
+
+impl From<WalletAccount> for AccountView {
+    fn from(account: WalletAccount) -> Self {
+        Self {
+            address: account.address(),
+            public_key: hex::encode(&account.public_key),
+            is_default: account.is_default,
+        }
+    }
+}
+
+/// An account together with the pieces of its on-chain state the `show` command
+/// surfaces: the sequence number and native balance, an optional single
+/// `--token` balance, and — when `--all-resources` is passed — every resource
+/// under the account decoded by [`ResourceView`].
+#[derive(Debug, Serialize, Deserialize)]
+pub struct AccountWithStateView {
+    pub auth_key_prefix: String,
+    pub account: WalletAccount,
+    pub sequence_number: Option<u64>,
+    pub balance: Option<u64>,
+    pub token_balance: Option<u64>,
+    /// Every resource under the account, each with its struct tag, raw hex, and
+    /// best-effort decode; `None` unless `--all-resources` was requested.
+    pub resources: Option<Vec<ResourceView>>,
+}
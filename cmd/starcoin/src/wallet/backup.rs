@@ -0,0 +1,172 @@
+// Copyright (c) The Starcoin Core Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+//! Encrypted, portable account backups, modeled on zcash-sync's `AccountBackup`.
+//!
+//! An account's secret material is serialized and sealed with ChaCha20-Poly1305
+//! under a key derived from a user-supplied passphrase with scrypt over a fresh
+//! random salt, using a fresh random 96-bit nonce. The emitted string is the
+//! salt, then the nonce, then the ciphertext, hex encoded; [`open_account`]
+//! reverses the process.
+
+use anyhow::{ensure, format_err, Result};
+use chacha20poly1305::aead::{Aead, NewAead};
+use chacha20poly1305::{ChaCha20Poly1305, Key, Nonce};
+use rand::rngs::OsRng;
+use rand::RngCore;
+use scrypt::{scrypt, Params};
+use serde::{Deserialize, Serialize};
+use starcoin_rpc_client::RpcClient;
+use starcoin_types::account_address::AccountAddress;
+use starcoin_wallet_api::WalletAccount;
+
+/// Length of the ChaCha20-Poly1305 nonce, in bytes (96 bits).
+const NONCE_LEN: usize = 12;
+
+/// Length of the per-backup KDF salt, in bytes.
+const SALT_LEN: usize = 16;
+
+/// The plaintext payload of a backup: the account address plus its private key
+/// material (the concatenated key set for a multisig account).
+#[derive(Debug, Serialize, Deserialize)]
+pub struct AccountBackup {
+    pub address: AccountAddress,
+    pub private_key: Vec<u8>,
+}
+
+impl AccountBackup {
+    pub fn new(address: AccountAddress, private_key: Vec<u8>) -> Self {
+        Self {
+            address,
+            private_key,
+        }
+    }
+}
+
+/// Derives a 32-byte ChaCha20-Poly1305 key from `passphrase` and `salt` with
+/// scrypt. A bare hash is not a KDF — no salt means identical passphrases yield
+/// identical keys, and no work factor makes the sealed blob cheap to brute
+/// force — so we stretch the passphrase with scrypt and store the random salt
+/// alongside the nonce.
+fn derive_key(passphrase: &[u8], salt: &[u8]) -> Result<Key> {
+    // log_n = 15, r = 8, p = 1 — the standard interactive-login scrypt cost.
+    let params = Params::new(15, 8, 1).map_err(|e| format_err!("invalid scrypt params: {}", e))?;
+    let mut key = [0u8; 32];
+    scrypt(passphrase, salt, &params, &mut key)
+        .map_err(|e| format_err!("key derivation failed: {}", e))?;
+    Ok(Key::clone_from_slice(&key))
+}
+
+/// Seals `backup` under `passphrase`, returning hex of `salt || nonce || ciphertext`.
+pub fn seal_account(backup: &AccountBackup, passphrase: &[u8]) -> Result<String> {
+    let plaintext = scs::to_bytes(backup)?;
+
+    let mut salt = [0u8; SALT_LEN];
+    OsRng.fill_bytes(&mut salt);
+    let cipher = ChaCha20Poly1305::new(&derive_key(passphrase, &salt)?);
+
+    let mut nonce_bytes = [0u8; NONCE_LEN];
+    OsRng.fill_bytes(&mut nonce_bytes);
+    let nonce = Nonce::from_slice(&nonce_bytes);
+
+    let ciphertext = cipher
+        .encrypt(nonce, plaintext.as_slice())
+        .map_err(|_| format_err!("failed to seal account backup"))?;
+
+    let mut sealed = salt.to_vec();
+    sealed.extend_from_slice(&nonce_bytes);
+    sealed.extend_from_slice(&ciphertext);
+    Ok(hex::encode(sealed))
+}
+
+/// Reverses [`seal_account`]: decodes, splits off the salt and nonce, and decrypts.
+pub fn open_account(sealed: &str, passphrase: &[u8]) -> Result<AccountBackup> {
+    let sealed = hex::decode(sealed)?;
+    ensure!(
+        sealed.len() > SALT_LEN + NONCE_LEN,
+        "sealed backup is too short to contain a salt and nonce"
+    );
+    let (salt, rest) = sealed.split_at(SALT_LEN);
+    let (nonce_bytes, ciphertext) = rest.split_at(NONCE_LEN);
+    let cipher = ChaCha20Poly1305::new(&derive_key(passphrase, salt)?);
+
+    let plaintext = cipher
+        .decrypt(Nonce::from_slice(nonce_bytes), ciphertext)
+        .map_err(|_| format_err!("failed to open account backup: wrong passphrase?"))?;
+    Ok(scs::from_bytes(&plaintext)?)
+}
+
+/// Secret-material access the `export`/`import` commands need from the wallet
+/// RPC. The commands depend only on this seam, which is implemented for
+/// [`RpcClient`] below on top of its account-service methods.
+pub trait WalletBackupClient {
+    /// Returns the account's private key material — the concatenated key set for
+    /// a multisig account — authorized by `password`.
+    fn wallet_export(&self, address: AccountAddress, password: &[u8]) -> Result<Vec<u8>>;
+
+    /// Re-registers an account from exported key material sealed under `password`.
+    fn wallet_import(
+        &self,
+        address: AccountAddress,
+        private_key: Vec<u8>,
+        password: &[u8],
+    ) -> Result<WalletAccount>;
+}
+
+/// Backs the `export`/`import` commands with the RPC client's existing
+/// account-service methods: `account_export` returns the stored private key
+/// material and `account_import` re-registers it. This keeps the secret on the
+/// node side and lets the CLI reuse the account password as the unlock
+/// credential, rather than introducing a brand-new secret-export RPC.
+impl WalletBackupClient for RpcClient {
+    fn wallet_export(&self, address: AccountAddress, password: &[u8]) -> Result<Vec<u8>> {
+        self.account_export(address, String::from_utf8_lossy(password).to_string())
+    }
+
+    fn wallet_import(
+        &self,
+        address: AccountAddress,
+        private_key: Vec<u8>,
+        password: &[u8],
+    ) -> Result<WalletAccount> {
+        self.account_import(
+            address,
+            private_key,
+            String::from_utf8_lossy(password).to_string(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_backup() -> AccountBackup {
+        AccountBackup::new(AccountAddress::random(), vec![1, 2, 3, 4, 5])
+    }
+
+    #[test]
+    fn seal_open_round_trips() {
+        let backup = sample_backup();
+        let sealed = seal_account(&backup, b"correct horse").unwrap();
+        let opened = open_account(&sealed, b"correct horse").unwrap();
+        assert_eq!(opened.address, backup.address);
+        assert_eq!(opened.private_key, backup.private_key);
+    }
+
+    #[test]
+    fn wrong_passphrase_is_rejected() {
+        let backup = sample_backup();
+        let sealed = seal_account(&backup, b"correct horse").unwrap();
+        assert!(open_account(&sealed, b"battery staple").is_err());
+    }
+
+    #[test]
+    fn fresh_salt_and_nonce_make_each_sealing_unique() {
+        let backup = sample_backup();
+        let first = seal_account(&backup, b"correct horse").unwrap();
+        let second = seal_account(&backup, b"correct horse").unwrap();
+        // Identical plaintext and passphrase must not yield identical ciphertext.
+        assert_ne!(first, second);
+    }
+}
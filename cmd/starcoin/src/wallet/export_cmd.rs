@@ -0,0 +1,52 @@
+// Copyright (c) The Starcoin Core Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::cli_state::CliState;
+use crate::wallet::backup::{seal_account, AccountBackup, WalletBackupClient};
+use crate::StarcoinOpt;
+use anyhow::{format_err, Result};
+use scmd::{CommandAction, ExecContext};
+use starcoin_types::account_address::AccountAddress;
+use structopt::StructOpt;
+
+/// Exports an account's secret material as a passphrase-sealed, portable backup.
+#[derive(Debug, StructOpt)]
+#[structopt(name = "export")]
+pub struct ExportOpt {
+    #[structopt(
+        short = "p",
+        long = "password",
+        name = "password",
+        help = "passphrase used to seal the exported key material",
+        default_value = ""
+    )]
+    password: String,
+    #[structopt(name = "account_address")]
+    account_address: AccountAddress,
+}
+
+pub struct ExportCommand;
+
+impl CommandAction for ExportCommand {
+    type State = CliState;
+    type GlobalOpt = StarcoinOpt;
+    type Opt = ExportOpt;
+    type ReturnItem = String;
+
+    fn run(
+        &self,
+        ctx: &ExecContext<Self::State, Self::GlobalOpt, Self::Opt>,
+    ) -> Result<Self::ReturnItem> {
+        let client = ctx.state().client();
+        let opt = ctx.opt();
+        let account = client.wallet_get(opt.account_address)?.ok_or_else(|| {
+            format_err!("Account with address {} not exist.", opt.account_address)
+        })?;
+
+        // Pull the account's private key (and, for a multisig account, the whole
+        // key set) out of the local store and seal it under the passphrase.
+        let private_key = client.wallet_export(opt.account_address, opt.password.as_bytes())?;
+        let backup = AccountBackup::new(account.address(), private_key);
+        seal_account(&backup, opt.password.as_bytes())
+    }
+}
@@ -0,0 +1,52 @@
+// Copyright (c) The Starcoin Core Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::cli_state::CliState;
+use crate::wallet::backup::{open_account, WalletBackupClient};
+use crate::view::AccountView;
+use crate::StarcoinOpt;
+use anyhow::Result;
+use scmd::{CommandAction, ExecContext};
+use structopt::StructOpt;
+
+/// Imports an account from a passphrase-sealed backup produced by `export`.
+#[derive(Debug, StructOpt)]
+#[structopt(name = "import")]
+pub struct ImportOpt {
+    #[structopt(
+        short = "p",
+        long = "password",
+        name = "password",
+        help = "passphrase the backup was sealed with",
+        default_value = ""
+    )]
+    password: String,
+    #[structopt(name = "backup", help = "sealed backup from `export`: salt, nonce and ciphertext, hex encoded")]
+    backup: String,
+}
+
+pub struct ImportCommand;
+
+impl CommandAction for ImportCommand {
+    type State = CliState;
+    type GlobalOpt = StarcoinOpt;
+    type Opt = ImportOpt;
+    type ReturnItem = AccountView;
+
+    fn run(
+        &self,
+        ctx: &ExecContext<Self::State, Self::GlobalOpt, Self::Opt>,
+    ) -> Result<Self::ReturnItem> {
+        let client = ctx.state().client();
+        let opt = ctx.opt();
+
+        // Decrypt the backup and re-register the account through the wallet API.
+        let backup = open_account(&opt.backup, opt.password.as_bytes())?;
+        let account = client.wallet_import(
+            backup.address,
+            backup.private_key,
+            opt.password.as_bytes(),
+        )?;
+        Ok(AccountView::from(account))
+    }
+}
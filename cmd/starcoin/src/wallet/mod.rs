@@ -0,0 +1,11 @@
+// Copyright (c) The Starcoin Core Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+mod backup;
+mod export_cmd;
+mod import_cmd;
+mod show_cmd;
+
+pub use export_cmd::*;
+pub use import_cmd::*;
+pub use show_cmd::*;
@@ -6,11 +6,15 @@ use crate::view::AccountWithStateView;
 use crate::StarcoinOpt;
 use anyhow::{format_err, Result};
 use scmd::{CommandAction, ExecContext};
+use serde::{Deserialize, Serialize};
 use starcoin_rpc_client::RemoteStateReader;
 use starcoin_state_api::AccountStateReader;
 use starcoin_types::account_address::AccountAddress;
+use starcoin_types::account_config::AccountResource;
+use starcoin_types::language_storage::StructTag;
 use starcoin_types::transaction::authenticator::AuthenticationKey;
 use starcoin_vm_types::parser;
+use std::convert::TryFrom;
 use structopt::StructOpt;
 
 #[derive(Debug, StructOpt)]
@@ -23,6 +27,12 @@ pub struct ShowOpt {
         help = "token's type tag, for example: 0x0::Starcoin::T"
     )]
     type_tag: Option<String>,
+    #[structopt(
+        long = "all-resources",
+        alias = "raw",
+        help = "dump every resource under the account, with raw hex and a best-effort decode"
+    )]
+    all_resources: bool,
     #[structopt(name = "account_address")]
     account_address: AccountAddress,
 }
@@ -61,6 +71,29 @@ impl CommandAction for ShowCommand {
             None => None,
         };
 
+        // When requested, fetch the account's entire state blob and decode every
+        // resource present, emitting its struct tag, raw hex, and a best-effort
+        // decoded representation (or `[undecodable]` when we have no layout).
+        let resources = if opt.all_resources {
+            account_state_reader
+                .get_account_state(account.address())?
+                .map(|account_state| {
+                    account_state
+                        .resource_iter()
+                        .map(|(struct_tag, blob)| {
+                            let decoded = decode_resource(&struct_tag, blob);
+                            ResourceView {
+                                struct_tag: struct_tag.to_string(),
+                                raw: hex::encode(blob),
+                                decoded,
+                            }
+                        })
+                        .collect()
+                })
+        } else {
+            None
+        };
+
         let auth_key_prefix = hex::encode(AuthenticationKey::ed25519(&account.public_key).prefix());
         Ok(AccountWithStateView {
             auth_key_prefix,
@@ -68,6 +101,32 @@ impl CommandAction for ShowCommand {
             sequence_number,
             balance,
             token_balance,
+            resources,
         })
     }
 }
+
+/// A single decoded resource under an account, as rendered by `show --all-resources`.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ResourceView {
+    /// The resource's fully-qualified struct tag, e.g. `0x0::Account::T`.
+    pub struct_tag: String,
+    /// The raw resource bytes, hex encoded.
+    pub raw: String,
+    /// A best-effort decode of the resource, or `[undecodable]` when the client
+    /// has no layout for it.
+    pub decoded: String,
+}
+
+/// Decodes the resources the client knows a layout for, falling back to
+/// `[undecodable]` so arbitrary on-chain state is still inspectable by hex.
+fn decode_resource(struct_tag: &StructTag, blob: &[u8]) -> String {
+    if *struct_tag == AccountResource::struct_tag() {
+        match AccountResource::try_from(blob) {
+            Ok(resource) => format!("{:?}", resource),
+            Err(_) => "[undecodable]".to_string(),
+        }
+    } else {
+        "[undecodable]".to_string()
+    }
+}
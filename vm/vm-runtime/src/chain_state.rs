@@ -4,10 +4,12 @@
 use crate::{
     access_path_helper::AccessPathHelper,
     account::AccountData,
+    error::ExecutorError,
 };
-use anyhow::{Error, Result};
+use anyhow::{format_err, Error, Result};
 use types::{
     access_path::AccessPath,
+    vm_error::{StatusCode, VMStatus},
     write_set::{WriteOp, WriteSet},
 };
 use libra_state_view::StateView;
@@ -29,17 +31,18 @@ impl<'txn> StateStore<'txn> {
     }
 
     /// Adds a [`WriteSet`] to state store.
-    pub fn add_write_set(&mut self, write_set: &WriteSet) {
+    pub fn add_write_set(&mut self, write_set: &WriteSet) -> Result<()> {
         for (access_path, write_op) in write_set {
             match write_op {
                 WriteOp::Value(blob) => {
-                    self.set(access_path.clone(), blob.clone());
+                    self.set(access_path.clone(), blob.clone())?;
                 }
                 WriteOp::Deletion => {
-                    self.remove(access_path);
+                    self.remove(access_path)?;
                 }
             }
         }
+        Ok(())
     }
 
     /// Sets a (key, value) pair within state store.
@@ -55,13 +58,16 @@ impl<'txn> StateStore<'txn> {
     }
 
     /// Adds an [`AccountData`] to state store.
-    pub fn add_account_data(&mut self, account_data: &AccountData) {
-        match account_data.to_resource().simple_serialize() {
-            Some(blob) => {
-                self.set(account_data.make_access_path(), blob);
-            }
-            None => panic!("can't create Account data"),
-        }
+    pub fn add_account_data(&mut self, account_data: &AccountData) -> Result<()> {
+        let access_path = account_data.make_access_path();
+        let blob = account_data
+            .to_resource()
+            .simple_serialize()
+            .ok_or_else(|| ExecutorError::StateCorrupt {
+                access_path: access_path.clone(),
+                source: format_err!("can't serialize Account data"),
+            })?;
+        self.set(access_path, blob)
     }
 
 }
@@ -86,6 +92,9 @@ impl<'txn> StateView for StateStore<'txn> {
 // This is used by the `process_transaction` API.
 impl<'txn> RemoteCache for StateStore<'txn> {
     fn get(&self, access_path: &LibraAccessPath) -> VMResult<Option<Vec<u8>>> {
-        Ok(StateView::get(self, access_path).expect("it should not error"))
+        StateView::get(self, access_path).map_err(|err| {
+            warn!("failed to read state at {:?}: {}", access_path, err);
+            VMStatus::new(StatusCode::STORAGE_ERROR).with_message(err.to_string())
+        })
     }
 }
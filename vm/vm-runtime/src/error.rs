@@ -0,0 +1,21 @@
+// Copyright (c) The Starcoin Core Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+use anyhow::Error;
+use thiserror::Error;
+use types::access_path::AccessPath;
+
+/// Errors raised while executing transactions against the backing `ChainState`.
+///
+/// A corrupted or partially-written blob should only abort the offending
+/// transaction, not the whole process, so state decode/read failures are
+/// surfaced as typed errors that callers can map onto a `TransactionStatus`.
+#[derive(Debug, Error)]
+pub enum ExecutorError {
+    /// A blob in the backing `ChainState` could not be read or decoded.
+    #[error("state corrupt at {access_path:?}: {source}")]
+    StateCorrupt {
+        access_path: AccessPath,
+        source: Error,
+    },
+}
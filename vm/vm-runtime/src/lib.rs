@@ -3,8 +3,10 @@
 
 pub mod access_path_helper;
 mod chain_state;
+pub mod error;
 pub mod starcoin_vm;
 mod transaction_helper;
 pub mod mock_vm;
+pub mod multi_ed25519;
 pub mod account;
 
@@ -2,19 +2,24 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::chain_state::StateStore;
+use crate::multi_ed25519::{
+    MultiEd25519Authenticator, MultiEd25519PublicKey, MultiEd25519Signature,
+};
 use anyhow::Result;
 use config::VMConfig;
-use crypto::ed25519::compat;
+use crypto::ed25519::{compat, Ed25519PrivateKey};
+use crypto::SigningKey;
 
 use once_cell::sync::Lazy;
 
-use std::convert::TryInto;
+use std::convert::{TryFrom, TryInto};
 
 use traits::ChainState;
 use types::{
     access_path::AccessPath,
     account_address::AccountAddress,
     account_config::AccountResource,
+    language_storage::TypeTag,
     transaction::{
         RawUserTransaction, Script, SignedUserTransaction, Transaction, TransactionArgument,
         TransactionOutput, TransactionPayload, TransactionStatus,
@@ -26,11 +31,17 @@ enum MockTransaction {
     Mint {
         sender: AccountAddress,
         amount: u64,
+        /// Token to credit; `None` mints the implicit native balance on
+        /// `AccountResource` for backward compatibility.
+        token: Option<TypeTag>,
     },
     Payment {
         sender: AccountAddress,
         recipient: AccountAddress,
         amount: u64,
+        /// Token to move; `None` falls back to the implicit native balance on
+        /// `AccountResource` for backward compatibility.
+        token: Option<TypeTag>,
     },
 }
 
@@ -42,18 +53,68 @@ pub static DISCARD_STATUS: Lazy<TransactionStatus> = Lazy::new(|| {
     TransactionStatus::Discard(VMStatus::new(StatusCode::ABORTED).with_sub_status(10))
 });
 
+// Sub status reserved for a transaction that touched a corrupt or undeserializable
+// blob in the backing state; distinct from the insufficient-balance code above so a
+// node operator can tell the two apart.
+pub static STATE_CORRUPT_STATUS: Lazy<TransactionStatus> = Lazy::new(|| {
+    TransactionStatus::Discard(VMStatus::new(StatusCode::STORAGE_ERROR).with_sub_status(1))
+});
+
+/// Decimal denomination the mock executor assumes for the native token: a mint
+/// `amount` expressed in whole-token units is scaled to base units as
+/// `amount * 10^DEFAULT_DENOMINATION`.
+///
+/// The mock has no token registry and keeps no per-token denomination: this one
+/// value scales every mint, native and token-typed alike.
+/// [`MockVM::set_denomination`] lets a caller override it for a test.
+pub const DEFAULT_DENOMINATION: u32 = 8;
+
+/// Default per-request faucet cap, in base units.
+pub const DEFAULT_FAUCET_WITHDRAWAL_LIMIT: u64 = 1_000_000_00000000;
+
+/// Default block reward, in base units.
+pub const DEFAULT_BLOCK_REWARD: u64 = 50_00000000;
+
 #[derive(Clone)]
 pub struct MockVM {
     config: VMConfig,
+    /// Decimal places a whole-token mint `amount` is scaled by.
+    denomination: u32,
+    /// Largest mint (in base units) the faucet will honour in a single request.
+    faucet_withdrawal_limit: u64,
+    /// Reward (in base units) credited to the block author per `BlockMetadata`.
+    block_reward: u64,
 }
 
 impl MockVM {
     pub fn new(config: &VMConfig) -> Self {
+        // A real executor would read these knobs from `VMConfig`; the mock keeps
+        // them as overridable fields seeded from the module defaults so the mock
+        // does not depend on config keys the node does not yet expose.
         Self {
             config: config.clone(),
+            denomination: DEFAULT_DENOMINATION,
+            faucet_withdrawal_limit: DEFAULT_FAUCET_WITHDRAWAL_LIMIT,
+            block_reward: DEFAULT_BLOCK_REWARD,
         }
     }
 
+    /// Overrides the denomination (decimal places) used to scale whole-token
+    /// mint amounts into base units, for every token.
+    pub fn set_denomination(&mut self, denomination: u32) {
+        self.denomination = denomination;
+    }
+
+    /// Overrides the per-request faucet cap, in base units.
+    pub fn set_faucet_withdrawal_limit(&mut self, limit: u64) {
+        self.faucet_withdrawal_limit = limit;
+    }
+
+    /// Overrides the per-block reward credited to the author, in base units.
+    pub fn set_block_reward(&mut self, reward: u64) {
+        self.block_reward = reward;
+    }
+
     pub fn create_account(
         &self,
         account_address: AccountAddress,
@@ -73,46 +134,117 @@ impl MockVM {
 
         match txn {
             Transaction::UserTransaction(txn) => match decode_transaction(&txn) {
-                MockTransaction::Mint { sender, amount } => {
-                    let access_path = AccessPath::new_for_account(sender);
-                    let account_resource: AccountResource = state_store
-                        .get_from_statedb(&access_path)?
-                        .unwrap()
-                        .try_into()?;
-                    let new_account_resource = AccountResource::new(
+                MockTransaction::Mint {
+                    sender,
+                    amount,
+                    token,
+                } => {
+                    // The account resource always holds the auth key; for a
+                    // non-native mint the balance lives on a token-scoped resource
+                    // keyed by the same auth key, so a later typed transfer can
+                    // spend it.
+                    let account_resource: AccountResource = match state_store
+                        .get_from_statedb(&AccessPath::new_for_account(sender))?
+                    {
+                        Some(blob) => match blob.try_into() {
+                            Ok(resource) => resource,
+                            Err(_) => return Ok(state_corrupt_output()),
+                        },
+                        None => return Ok(state_corrupt_output()),
+                    };
+                    // `amount` is given in whole-token units; scale it into base
+                    // units and reject anything over the faucet cap rather than
+                    // silently overwriting the balance.
+                    let minted = match faucet_mint_amount(
                         amount,
+                        self.denomination,
+                        self.faucet_withdrawal_limit,
+                    ) {
+                        Some(minted) => minted,
+                        None => {
+                            return Ok(TransactionOutput::new(
+                                vec![],
+                                0,
+                                DISCARD_STATUS.clone(),
+                            ));
+                        }
+                    };
+                    let access_path = balance_access_path(sender, &token);
+                    let new_account_resource = AccountResource::new(
+                        minted,
                         1,
                         account_resource.authentication_key().clone(),
                     );
-                    state_store
-                        .set(access_path, new_account_resource.try_into()?)
-                        .unwrap();
+                    state_store.set(access_path, new_account_resource.try_into()?)?;
                     output = TransactionOutput::new(vec![], 0, KEEP_STATUS.clone());
                 }
                 MockTransaction::Payment {
                     sender,
                     recipient,
                     amount,
+                    token,
                 } => {
-                    let access_path_sender = AccessPath::new_for_account(sender);
-                    let access_path_receiver = AccessPath::new_for_account(recipient);
+                    // Balances are kept per token: the native balance lives on the
+                    // account's `AccountResource`, while every other token gets its
+                    // own balance resource created lazily on first use.
+                    let access_path_sender = balance_access_path(sender, &token);
+                    let access_path_receiver = balance_access_path(recipient, &token);
 
-                    let account_resource_sender: AccountResource = state_store
+                    let account_resource_sender: AccountResource = match state_store
                         .get_from_statedb(&access_path_sender)?
-                        .expect("txn sender must exist.")
-                        .try_into()?;
-                    let account_resource_receiver: AccountResource = state_store
-                        .get_from_statedb(&access_path_receiver)
-                        .and_then(|blob| match blob {
-                            Some(blob) => Ok(blob),
-                            None => {
-                                state_store.create_account(recipient)?;
-                                Ok(state_store
-                                    .get_from_statedb(&access_path_receiver)?
-                                    .expect("account resource must exist."))
+                    {
+                        Some(blob) => match blob.try_into() {
+                            Ok(resource) => resource,
+                            Err(_) => return Ok(state_corrupt_output()),
+                        },
+                        // A transfer of a token the sender has never held is valid
+                        // input but has no balance resource yet; treat it as a zero
+                        // balance and discard rather than panicking the node.
+                        None => {
+                            return Ok(TransactionOutput::new(vec![], 0, DISCARD_STATUS.clone()));
+                        }
+                    };
+                    let account_resource_receiver: AccountResource = match state_store
+                        .get_from_statedb(&access_path_receiver)?
+                    {
+                        Some(blob) => match blob.try_into() {
+                            Ok(resource) => resource,
+                            Err(_) => return Ok(state_corrupt_output()),
+                        },
+                        None => {
+                            // Lazily create the recipient account, then its token
+                            // balance resource if this is not the native token.
+                            state_store.create_account(recipient)?;
+                            let blob = match state_store.get_from_statedb(&access_path_receiver)? {
+                                Some(blob) => blob,
+                                None => {
+                                    // Non-native token: seed a zero balance resource
+                                    // from the freshly created account's auth key.
+                                    let account = match state_store
+                                        .get_from_statedb(&AccessPath::new_for_account(recipient))?
+                                    {
+                                        Some(account) => account,
+                                        None => return Ok(state_corrupt_output()),
+                                    };
+                                    let auth_key = match AccountResource::try_from(account.as_slice())
+                                    {
+                                        Ok(resource) => resource.authentication_key().clone(),
+                                        Err(_) => return Ok(state_corrupt_output()),
+                                    };
+                                    let blob = AccountResource::new(0, 0, auth_key).try_into()?;
+                                    state_store.set(access_path_receiver.clone(), blob)?;
+                                    match state_store.get_from_statedb(&access_path_receiver)? {
+                                        Some(blob) => blob,
+                                        None => return Ok(state_corrupt_output()),
+                                    }
+                                }
+                            };
+                            match blob.try_into() {
+                                Ok(resource) => resource,
+                                Err(_) => return Ok(state_corrupt_output()),
                             }
-                        })
-                        .and_then(|blob| blob.try_into())?;
+                        }
+                    };
 
                     let balance_sender = account_resource_sender.balance();
                     let balance_receiver = account_resource_receiver.balance();
@@ -147,23 +279,32 @@ impl MockVM {
                 }
             },
             Transaction::BlockMetadata(block_metadata) => {
-                let (_id, _timestamp, author) = block_metadata.into_inner().unwrap();
+                let (_id, _timestamp, author) = match block_metadata.into_inner() {
+                    Ok(inner) => inner,
+                    Err(_) => return Ok(state_corrupt_output()),
+                };
                 let access_path = AccessPath::new_for_account(author);
-                let account_resource: AccountResource = state_store
-                    .get_from_statedb(&access_path)
-                    .and_then(|blob| match blob {
-                        Some(blob) => Ok(blob),
-                        None => {
-                            state_store.create_account(author)?;
-                            Ok(state_store
-                                .get_from_statedb(&access_path)?
-                                .expect("account resource must exist."))
+                let account_resource: AccountResource = match state_store
+                    .get_from_statedb(&access_path)?
+                {
+                    Some(blob) => match blob.try_into() {
+                        Ok(resource) => resource,
+                        Err(_) => return Ok(state_corrupt_output()),
+                    },
+                    None => {
+                        state_store.create_account(author)?;
+                        match state_store.get_from_statedb(&access_path)? {
+                            Some(blob) => match blob.try_into() {
+                                Ok(resource) => resource,
+                                Err(_) => return Ok(state_corrupt_output()),
+                            },
+                            None => return Ok(state_corrupt_output()),
                         }
-                    })
-                    .and_then(|blob| blob.try_into())?;
+                    }
+                };
 
                 let new_account_resource = AccountResource::new(
-                    account_resource.balance() + 50_00000000,
+                    account_resource.balance() + self.block_reward,
                     account_resource.sequence_number(),
                     account_resource.authentication_key().clone(),
                 );
@@ -182,27 +323,95 @@ impl MockVM {
     }
 }
 
+/// A transaction output discarding the offending transaction because it touched
+/// a corrupt or undeserializable blob in the backing state, leaving the node
+/// running rather than panicking.
+fn state_corrupt_output() -> TransactionOutput {
+    TransactionOutput::new(vec![], 0, STATE_CORRUPT_STATUS.clone())
+}
+
+/// Scales a whole-token `amount` into base units (`amount * 10^denomination`),
+/// returning `None` on overflow so the caller can discard the transaction.
+fn scale_to_base_units(amount: u64, denomination: u32) -> Option<u64> {
+    10u64
+        .checked_pow(denomination)
+        .and_then(|factor| amount.checked_mul(factor))
+}
+
+/// Scales `amount` into base units and enforces the faucet cap, returning the
+/// minted base-unit amount or `None` when it overflows or exceeds `limit`.
+fn faucet_mint_amount(amount: u64, denomination: u32, limit: u64) -> Option<u64> {
+    match scale_to_base_units(amount, denomination) {
+        Some(minted) if minted <= limit => Some(minted),
+        _ => None,
+    }
+}
+
 pub fn encode_mint_program(amount: u64) -> Script {
     let argument = TransactionArgument::U64(amount);
     Script::new(vec![], vec![argument])
 }
 
-pub fn encode_transfer_program(recipient: AccountAddress, amount: u64) -> Script {
+pub fn encode_mint_token_program(amount: u64, token: &TypeTag) -> Script {
+    let argument1 = TransactionArgument::U64(amount);
+    // Carry the token type tag the same way `encode_transfer_program` does, so a
+    // mint and a later transfer agree on which balance is being credited.
+    let argument2 = TransactionArgument::U8Vector(scs::to_bytes(token).expect("serialize TypeTag"));
+    Script::new(vec![], vec![argument1, argument2])
+}
+
+pub fn encode_transfer_program(
+    recipient: AccountAddress,
+    amount: u64,
+    token: &TypeTag,
+) -> Script {
     let argument1 = TransactionArgument::Address(recipient);
     let argument2 = TransactionArgument::U64(amount);
-    Script::new(vec![], vec![argument1, argument2])
+    // Carry the token type tag as its serialized bytes so the decoder agrees
+    // with the `--token` type tag accepted by the `show` command.
+    let argument3 = TransactionArgument::U8Vector(scs::to_bytes(token).expect("serialize TypeTag"));
+    Script::new(vec![], vec![argument1, argument2, argument3])
+}
+
+/// Returns the access path holding `address`'s balance for `token`: the native
+/// `AccountResource` when `token` is `None`, a per-token balance resource otherwise.
+///
+/// There is no `AccessPath::new_for_token` constructor in the `types` crate, so
+/// rather than add one we derive the token-scoped path from the existing
+/// `AccessPath::new_for_account` path, appending the SCS-serialized token tag and
+/// rebuilding with `AccessPath::new`. This keeps the path deterministic and
+/// collision-free per `(address, token)` without extending the `types` API.
+fn balance_access_path(address: AccountAddress, token: &Option<TypeTag>) -> AccessPath {
+    match token {
+        Some(token) => {
+            let account_path = AccessPath::new_for_account(address);
+            let mut path = account_path.path;
+            path.extend_from_slice(&scs::to_bytes(token).expect("serialize TypeTag"));
+            AccessPath::new(address, path)
+        }
+        None => AccessPath::new_for_account(address),
+    }
 }
 
 pub fn encode_mint_transaction(sender: AccountAddress, amount: u64) -> Transaction {
     encode_transaction(sender, encode_mint_program(amount))
 }
 
+pub fn encode_mint_token_transaction(
+    sender: AccountAddress,
+    amount: u64,
+    token: &TypeTag,
+) -> Transaction {
+    encode_transaction(sender, encode_mint_token_program(amount, token))
+}
+
 pub fn encode_transfer_transaction(
     sender: AccountAddress,
     recipient: AccountAddress,
     amount: u64,
+    token: &TypeTag,
 ) -> Transaction {
-    encode_transaction(sender, encode_transfer_program(recipient, amount))
+    encode_transaction(sender, encode_transfer_program(recipient, amount, token))
 }
 
 fn encode_transaction(sender: AccountAddress, program: Script) -> Transaction {
@@ -218,6 +427,49 @@ fn encode_transaction(sender: AccountAddress, program: Script) -> Transaction {
     )
 }
 
+/// Signs `raw_transaction` with a `K`-of-`N` multisig key set, parallel to the
+/// single-key `raw_transaction.sign(..)` path.
+///
+/// `signers` pairs each contributing private key with its index into
+/// `public_key`; at least `public_key.threshold()` of them must be supplied.
+/// Returns the assembled [`MultiEd25519Signature`], which verifies against the
+/// raw transaction bytes under `public_key`.
+pub fn sign_multi(
+    raw_transaction: &RawUserTransaction,
+    public_key: &MultiEd25519PublicKey,
+    signers: &[(Ed25519PrivateKey, u8)],
+) -> Result<MultiEd25519Signature> {
+    let message = scs::to_bytes(raw_transaction)?;
+    let signatures = signers
+        .iter()
+        .map(|(private_key, index)| {
+            (private_key.sign_arbitrary_message(&message), *index)
+        })
+        .collect();
+    let signature = MultiEd25519Signature::new(signatures)?;
+    signature.verify(&message, public_key)?;
+    Ok(signature)
+}
+
+/// Signs `raw_transaction` with a `K`-of-`N` key set and assembles a multisig
+/// [`MultiEd25519Authenticator`] — the public keys together with the verified
+/// signature — rather than a bare signature.
+///
+/// This is the multisig analogue of the single-key `raw_transaction.sign(..)`
+/// path: the returned authenticator carries the key set it was signed under, so
+/// its [`authentication_key`] matches the account controlled by the keys and its
+/// signature can be re-verified with [`MultiEd25519Authenticator::verify`].
+///
+/// [`authentication_key`]: MultiEd25519Authenticator::authentication_key
+pub fn sign_multi_authenticator(
+    raw_transaction: &RawUserTransaction,
+    public_key: &MultiEd25519PublicKey,
+    signers: &[(Ed25519PrivateKey, u8)],
+) -> Result<MultiEd25519Authenticator> {
+    let signature = sign_multi(raw_transaction, public_key, signers)?;
+    Ok(MultiEd25519Authenticator::new(public_key.clone(), signature))
+}
+
 fn decode_transaction(txn: &SignedUserTransaction) -> MockTransaction {
     let sender = txn.sender();
     match txn.payload() {
@@ -225,7 +477,11 @@ fn decode_transaction(txn: &SignedUserTransaction) -> MockTransaction {
             assert!(script.code().is_empty(), "Code should be empty.");
             match script.args().len() {
                 1 => match script.args()[0] {
-                    TransactionArgument::U64(amount) => MockTransaction::Mint { sender, amount },
+                    TransactionArgument::U64(amount) => MockTransaction::Mint {
+                        sender,
+                        amount,
+                        token: None,
+                    },
                     _ => unimplemented!(
                         "Only one integer argument is allowed for mint transactions."
                     ),
@@ -236,6 +492,16 @@ fn decode_transaction(txn: &SignedUserTransaction) -> MockTransaction {
                             sender,
                             recipient: *recipient,
                             amount: *amount,
+                            token: None,
+                        }
+                    }
+                    // A typed mint: amount first, then the serialized token tag,
+                    // distinguished from a native payment by the argument types.
+                    (TransactionArgument::U64(amount), TransactionArgument::U8Vector(token)) => {
+                        MockTransaction::Mint {
+                            sender,
+                            amount: *amount,
+                            token: Some(scs::from_bytes(token).expect("deserialize TypeTag")),
                         }
                     }
                     _ => unimplemented!(
@@ -243,7 +509,23 @@ fn decode_transaction(txn: &SignedUserTransaction) -> MockTransaction {
                          and the second argument must be amount."
                     ),
                 },
-                _ => unimplemented!("Transaction must have one or two arguments.{:?}", txn),
+                3 => match (&script.args()[0], &script.args()[1], &script.args()[2]) {
+                    (
+                        TransactionArgument::Address(recipient),
+                        TransactionArgument::U64(amount),
+                        TransactionArgument::U8Vector(token),
+                    ) => MockTransaction::Payment {
+                        sender,
+                        recipient: *recipient,
+                        amount: *amount,
+                        token: Some(scs::from_bytes(token).expect("deserialize TypeTag")),
+                    },
+                    _ => unimplemented!(
+                        "A typed payment transaction takes a recipient address, an amount, and \
+                         the serialized token type tag."
+                    ),
+                },
+                _ => unimplemented!("Transaction must have one to three arguments.{:?}", txn),
             }
         }
         TransactionPayload::Module(_) => {
@@ -254,3 +536,137 @@ fn decode_transaction(txn: &SignedUserTransaction) -> MockTransaction {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crypto::ed25519::compat;
+
+    fn raw_mint_transaction(sender: AccountAddress) -> RawUserTransaction {
+        RawUserTransaction::new_script(
+            sender,
+            0,
+            encode_mint_program(1),
+            0,
+            0,
+            std::time::Duration::from_secs(0),
+        )
+    }
+
+    #[test]
+    fn mint_amount_is_scaled_by_denomination() {
+        // A whole-token amount is scaled into base units by 10^denomination.
+        assert_eq!(faucet_mint_amount(5, 8, DEFAULT_FAUCET_WITHDRAWAL_LIMIT), Some(5_00000000));
+        assert_eq!(faucet_mint_amount(1, 0, DEFAULT_FAUCET_WITHDRAWAL_LIMIT), Some(1));
+    }
+
+    #[test]
+    fn mint_over_the_faucet_cap_is_rejected() {
+        // Scaling the whole-token amount past the cap yields `None` so the Mint
+        // arm discards instead of fabricating the balance.
+        assert_eq!(faucet_mint_amount(2, 8, 1_00000000), None);
+        // Overflowing the scale is likewise rejected rather than wrapping.
+        assert_eq!(faucet_mint_amount(u64::MAX, 8, u64::MAX), None);
+    }
+
+    #[test]
+    fn mint_decodes_native_and_token_variants() {
+        let sender = AccountAddress::random();
+
+        // A one-argument mint targets the native balance.
+        let native = encode_transaction(sender, encode_mint_program(7));
+        match native {
+            Transaction::UserTransaction(txn) => match decode_transaction(&txn) {
+                MockTransaction::Mint { amount, token, .. } => {
+                    assert_eq!(amount, 7);
+                    assert!(token.is_none());
+                }
+                _ => panic!("expected a mint"),
+            },
+            _ => panic!("expected a user transaction"),
+        }
+
+        // A typed mint carries the token tag so a later transfer can spend it.
+        let typed = encode_mint_token_transaction(sender, 9, &TypeTag::U64);
+        match typed {
+            Transaction::UserTransaction(txn) => match decode_transaction(&txn) {
+                MockTransaction::Mint { amount, token, .. } => {
+                    assert_eq!(amount, 9);
+                    assert_eq!(token, Some(TypeTag::U64));
+                }
+                _ => panic!("expected a mint"),
+            },
+            _ => panic!("expected a user transaction"),
+        }
+    }
+
+    #[test]
+    fn balance_access_path_is_token_scoped() {
+        let address = AccountAddress::random();
+        let token_a = TypeTag::Bool;
+        let token_b = TypeTag::U64;
+
+        // The native balance lives on the account resource path.
+        assert_eq!(
+            balance_access_path(address, &None),
+            AccessPath::new_for_account(address)
+        );
+        // Distinct tokens get distinct paths, and none collide with the native path.
+        let path_a = balance_access_path(address, &Some(token_a));
+        let path_b = balance_access_path(address, &Some(token_b));
+        assert_ne!(path_a, path_b);
+        assert_ne!(path_a, AccessPath::new_for_account(address));
+    }
+
+    #[test]
+    fn sign_multi_round_trips() {
+        let sender = AccountAddress::random();
+        let raw = raw_mint_transaction(sender);
+        let (priv0, pub0) = compat::generate_keypair(None);
+        let (priv1, pub1) = compat::generate_keypair(None);
+        let (priv2, pub2) = compat::generate_keypair(None);
+        let public_key = MultiEd25519PublicKey::new(vec![pub0, pub1, pub2], 2).unwrap();
+
+        // Two of the three keys sign; the assembled signature must verify.
+        let signature = sign_multi(&raw, &public_key, &[(priv0, 0), (priv2, 2)]).unwrap();
+        let message = scs::to_bytes(&raw).unwrap();
+        assert!(signature.verify(&message, &public_key).is_ok());
+    }
+
+    #[test]
+    fn sign_multi_authenticator_round_trips() {
+        let sender = AccountAddress::random();
+        let raw = raw_mint_transaction(sender);
+        let (priv0, pub0) = compat::generate_keypair(None);
+        let (priv1, pub1) = compat::generate_keypair(None);
+        let (_priv2, pub2) = compat::generate_keypair(None);
+        let public_key =
+            MultiEd25519PublicKey::new(vec![pub0, pub1, pub2], 2).unwrap();
+
+        // Assemble a multisig authenticator and round-trip it: its signature
+        // re-verifies against the raw transaction bytes and its authentication
+        // key matches the account the key set controls.
+        let authenticator =
+            sign_multi_authenticator(&raw, &public_key, &[(priv0, 0), (priv1, 1)]).unwrap();
+        let message = scs::to_bytes(&raw).unwrap();
+        assert!(authenticator.verify(&message).is_ok());
+        assert_eq!(
+            authenticator.authentication_key(),
+            public_key.authentication_key()
+        );
+    }
+
+    #[test]
+    fn sign_multi_below_threshold_is_rejected() {
+        let sender = AccountAddress::random();
+        let raw = raw_mint_transaction(sender);
+        let (priv0, pub0) = compat::generate_keypair(None);
+        let (_priv1, pub1) = compat::generate_keypair(None);
+        let (_priv2, pub2) = compat::generate_keypair(None);
+        let public_key = MultiEd25519PublicKey::new(vec![pub0, pub1, pub2], 2).unwrap();
+
+        // Only one signer for a 2-of-3 key: assembly verifies the threshold and
+        // therefore fails rather than producing a usable signature.
+        assert!(sign_multi(&raw, &public_key, &[(priv0, 0)]).is_err());
+    }
+}
@@ -0,0 +1,258 @@
+// Copyright (c) The Starcoin Core Contributors
+// SPDX-License-Identifier: Apache-2.0
+
+//! K-of-N MultiEd25519 multisig primitives.
+//!
+//! An account may be controlled by a threshold `K` of `N` ed25519 keys, as
+//! Diem/Libra model with their `MultiEd25519` authenticator. A
+//! [`MultiEd25519PublicKey`] is an ordered list of `N` public keys plus a
+//! threshold byte `K` (`K <= N <= 32`); a [`MultiEd25519Signature`] is the
+//! concatenation of up to `N` individual signatures followed by a 4-byte
+//! big-endian bitmap whose `i`-th set bit means key `i` signed.
+
+use anyhow::{bail, ensure, Result};
+use crypto::ed25519::{Ed25519PublicKey, Ed25519Signature};
+use crypto::{Signature, ValidKey};
+use sha3::{Digest, Sha3_256};
+
+// `Signature::verify_arbitrary_msg` validates a raw byte message under a single key.
+
+/// The maximum number of keys a multisig account may be composed of.
+pub const MAX_NUM_OF_KEYS: usize = 32;
+
+/// Authentication-key scheme byte for the single-key ed25519 scheme.
+pub const ED25519_SCHEME: u8 = 0;
+/// Authentication-key scheme byte for the multi-key ed25519 scheme.
+pub const MULTI_ED25519_SCHEME: u8 = 1;
+
+/// An ordered set of `N` ed25519 public keys together with a `K`-of-`N`
+/// signing threshold.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MultiEd25519PublicKey {
+    public_keys: Vec<Ed25519PublicKey>,
+    threshold: u8,
+}
+
+impl MultiEd25519PublicKey {
+    /// Builds a multisig public key, checking that `0 < threshold <= N <= 32`.
+    pub fn new(public_keys: Vec<Ed25519PublicKey>, threshold: u8) -> Result<Self> {
+        let n = public_keys.len();
+        ensure!(threshold > 0, "threshold must be positive");
+        ensure!(
+            n >= threshold as usize,
+            "threshold {} exceeds the number of keys {}",
+            threshold,
+            n
+        );
+        ensure!(
+            n <= MAX_NUM_OF_KEYS,
+            "a multisig account may hold at most {} keys, got {}",
+            MAX_NUM_OF_KEYS,
+            n
+        );
+        Ok(Self {
+            public_keys,
+            threshold,
+        })
+    }
+
+    pub fn public_keys(&self) -> &[Ed25519PublicKey] {
+        &self.public_keys
+    }
+
+    pub fn threshold(&self) -> u8 {
+        self.threshold
+    }
+
+    /// The authentication key is `sha3_256(concat(pubkey_bytes) || scheme_byte)`,
+    /// with [`MULTI_ED25519_SCHEME`] disambiguating it from the single-key scheme.
+    pub fn authentication_key(&self) -> [u8; 32] {
+        let mut hasher = Sha3_256::new();
+        for public_key in &self.public_keys {
+            hasher.input(public_key.to_bytes());
+        }
+        hasher.input([MULTI_ED25519_SCHEME]);
+        let mut auth_key = [0u8; 32];
+        auth_key.copy_from_slice(hasher.result().as_slice());
+        auth_key
+    }
+}
+
+/// Up to `N` ed25519 signatures plus a big-endian bitmap recording which keys
+/// produced them.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MultiEd25519Signature {
+    signatures: Vec<Ed25519Signature>,
+    bitmap: [u8; 4],
+}
+
+impl MultiEd25519Signature {
+    /// Assembles a signature from `(signature, key index)` pairs, setting one
+    /// bitmap bit per signer. Indices must be unique and below [`MAX_NUM_OF_KEYS`].
+    pub fn new(signatures: Vec<(Ed25519Signature, u8)>) -> Result<Self> {
+        ensure!(
+            signatures.len() <= MAX_NUM_OF_KEYS,
+            "too many signatures: {}",
+            signatures.len()
+        );
+        let mut bitmap = [0u8; 4];
+        let mut sorted = signatures;
+        sorted.sort_by_key(|(_, index)| *index);
+        let mut collected = Vec::with_capacity(sorted.len());
+        for (signature, index) in sorted {
+            ensure!(
+                (index as usize) < MAX_NUM_OF_KEYS,
+                "signature index {} out of range",
+                index
+            );
+            ensure!(!bitmap_get(&bitmap, index), "duplicate signature index {}", index);
+            bitmap_set(&mut bitmap, index);
+            collected.push(signature);
+        }
+        Ok(Self {
+            signatures: collected,
+            bitmap,
+        })
+    }
+
+    pub fn signatures(&self) -> &[Ed25519Signature] {
+        &self.signatures
+    }
+
+    pub fn bitmap(&self) -> &[u8; 4] {
+        &self.bitmap
+    }
+
+    /// Verifies the signature against `message` under `public_key`, checking
+    /// that at least `threshold` keys signed and that every set bit indexes a
+    /// present signature whose bytes validate under the matching key.
+    pub fn verify(&self, message: &[u8], public_key: &MultiEd25519PublicKey) -> Result<()> {
+        let num_sigs = self.signatures.len();
+        ensure!(
+            num_sigs == bitmap_count_ones(&self.bitmap) as usize,
+            "signature count {} does not match bitmap",
+            num_sigs
+        );
+        ensure!(
+            num_sigs >= public_key.threshold as usize,
+            "only {} signatures for a {}-of-{} key",
+            num_sigs,
+            public_key.threshold,
+            public_key.public_keys.len()
+        );
+        let mut signature_iter = self.signatures.iter();
+        for index in 0..(MAX_NUM_OF_KEYS as u8) {
+            if bitmap_get(&self.bitmap, index) {
+                let key = public_key
+                    .public_keys
+                    .get(index as usize)
+                    .ok_or_else(|| anyhow::anyhow!("bitmap bit {} has no key", index))?;
+                let signature = match signature_iter.next() {
+                    Some(signature) => signature,
+                    None => bail!("bitmap bit {} has no signature", index),
+                };
+                signature.verify_arbitrary_msg(message, key)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// A multisig authenticator: the public-key set paired with the assembled
+/// signature, the multisig analogue of the `(Ed25519PublicKey, Ed25519Signature)`
+/// a single-key `SignedUserTransaction` carries.
+///
+/// It yields the controlled account's [`authentication_key`] and re-checks its
+/// own signature with [`MultiEd25519Authenticator::verify`], so callers hold a
+/// self-describing, verifiable authenticator rather than a bare signature with
+/// no bound keys.
+///
+/// [`authentication_key`]: MultiEd25519Authenticator::authentication_key
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MultiEd25519Authenticator {
+    public_key: MultiEd25519PublicKey,
+    signature: MultiEd25519Signature,
+}
+
+impl MultiEd25519Authenticator {
+    pub fn new(public_key: MultiEd25519PublicKey, signature: MultiEd25519Signature) -> Self {
+        Self {
+            public_key,
+            signature,
+        }
+    }
+
+    pub fn public_key(&self) -> &MultiEd25519PublicKey {
+        &self.public_key
+    }
+
+    pub fn signature(&self) -> &MultiEd25519Signature {
+        &self.signature
+    }
+
+    /// The authentication key the controlled account is created under, derived
+    /// from the key set and the multi-key scheme byte.
+    pub fn authentication_key(&self) -> [u8; 32] {
+        self.public_key.authentication_key()
+    }
+
+    /// Verifies the carried signature against `message` under the carried keys.
+    pub fn verify(&self, message: &[u8]) -> Result<()> {
+        self.signature.verify(message, &self.public_key)
+    }
+}
+
+fn bitmap_set(bitmap: &mut [u8; 4], index: u8) {
+    bitmap[(index / 8) as usize] |= 128 >> (index % 8);
+}
+
+fn bitmap_get(bitmap: &[u8; 4], index: u8) -> bool {
+    bitmap[(index / 8) as usize] & (128 >> (index % 8)) != 0
+}
+
+fn bitmap_count_ones(bitmap: &[u8; 4]) -> u32 {
+    bitmap.iter().map(|byte| byte.count_ones()).sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crypto::ed25519::compat;
+    use crypto::SigningKey;
+
+    #[test]
+    fn threshold_bounds_are_enforced() {
+        let (_priv0, pub0) = compat::generate_keypair(None);
+        let (_priv1, pub1) = compat::generate_keypair(None);
+        assert!(MultiEd25519PublicKey::new(vec![pub0.clone(), pub1.clone()], 0).is_err());
+        assert!(MultiEd25519PublicKey::new(vec![pub0, pub1], 3).is_err());
+    }
+
+    #[test]
+    fn authentication_key_uses_the_multi_scheme_byte() {
+        let (_priv0, pub0) = compat::generate_keypair(None);
+        let public_key = MultiEd25519PublicKey::new(vec![pub0.clone()], 1).unwrap();
+
+        let mut hasher = Sha3_256::new();
+        hasher.input(pub0.to_bytes());
+        hasher.input([MULTI_ED25519_SCHEME]);
+        let mut expected = [0u8; 32];
+        expected.copy_from_slice(hasher.result().as_slice());
+        assert_eq!(public_key.authentication_key(), expected);
+    }
+
+    #[test]
+    fn verify_rejects_a_signature_under_the_wrong_key() {
+        let message = b"multi ed25519 message";
+        let (priv0, pub0) = compat::generate_keypair(None);
+        let (priv1, pub1) = compat::generate_keypair(None);
+        let public_key = MultiEd25519PublicKey::new(vec![pub0, pub1], 2).unwrap();
+
+        // Both keys sign, but swap the bitmap indices so each signature is
+        // checked against the other key; verification must fail.
+        let sig0 = priv0.sign_arbitrary_message(message);
+        let sig1 = priv1.sign_arbitrary_message(message);
+        let signature = MultiEd25519Signature::new(vec![(sig1, 0), (sig0, 1)]).unwrap();
+        assert!(signature.verify(message, &public_key).is_err());
+    }
+}